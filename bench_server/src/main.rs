@@ -13,22 +13,15 @@ struct HelloResponse {
     message: &'static str,
 }
 
-#[derive(Serialize, Schema)]
+#[derive(Serialize, Deserialize, Schema)]
 struct UserResponse {
     id: i64,
     name: String,
     email: String,
-    created_at: &'static str,
+    created_at: String,
     is_active: bool,
 }
 
-#[derive(Serialize, Schema)]
-struct UsersListResponse {
-    users: Vec<UserResponse>,
-    total: usize,
-    page: usize,
-}
-
 #[derive(Serialize, Schema)]
 struct PostResponse {
     post_id: i64,
@@ -42,6 +35,30 @@ struct CreateUser {
     name: String,
     #[validate(email)]
     email: String,
+    #[validate(length(min = 8))]
+    password: String,
+    confirm_password: String,
+}
+
+// Cross-field rules that don't fit a per-field `#[validate(...)]` attribute run
+// after the derived `Validate` pass and accumulate into the same error map.
+impl Check for CreateUser {
+    fn check(&self, errors: &mut FieldErrors) {
+        assert_length(errors, "name", &self.name, 1, 100, "name must be 1-100 chars");
+        if self.password != self.confirm_password {
+            errors.add("confirm_password", "passwords do not match");
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct VisitSession {
+    views: u32,
+}
+
+#[derive(Serialize, Schema)]
+struct VisitResponse {
+    views: u32,
 }
 
 // ============================================
@@ -66,8 +83,24 @@ async fn json_hello() -> Json<HelloResponse> {
     })
 }
 
+/// Static route ranked ahead of `/users/{id}` so it isn't shadowed by the dynamic segment
+#[rustapi_rs::get("/users/me")]
+#[rustapi_rs::rank(0)]
+#[rustapi_rs::tag("Benchmark")]
+#[rustapi_rs::summary("Get the current user")]
+async fn get_current_user() -> Json<UserResponse> {
+    Json(UserResponse {
+        id: 0,
+        name: "Current User".to_string(),
+        email: "me@example.com".to_string(),
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        is_active: true,
+    })
+}
+
 /// JSON response with path parameter
 #[rustapi_rs::get("/users/{id}")]
+#[rustapi_rs::rank(1)]
 #[rustapi_rs::tag("Benchmark")]
 #[rustapi_rs::summary("Get user by ID")]
 async fn get_user(Path(id): Path<i64>) -> Json<UserResponse> {
@@ -75,13 +108,14 @@ async fn get_user(Path(id): Path<i64>) -> Json<UserResponse> {
         id,
         name: format!("User {}", id),
         email: format!("user{}@example.com", id),
-        created_at: "2024-01-01T00:00:00Z",
+        created_at: "2024-01-01T00:00:00Z".to_string(),
         is_active: true,
     })
 }
 
 /// JSON response with path parameter
 #[rustapi_rs::get("/posts/{id}")]
+#[rustapi_rs::format("application/json")]
 #[rustapi_rs::tag("Benchmark")]
 #[rustapi_rs::summary("Get post by ID")]
 async fn get_post(Path(id): Path<i64>) -> Json<PostResponse> {
@@ -92,6 +126,15 @@ async fn get_post(Path(id): Path<i64>) -> Json<PostResponse> {
     })
 }
 
+/// Same route as `get_post`, negotiated via `Accept: text/plain` instead of JSON
+#[rustapi_rs::get("/posts/{id}")]
+#[rustapi_rs::format("text/plain")]
+#[rustapi_rs::tag("Benchmark")]
+#[rustapi_rs::summary("Get post by ID (plain text)")]
+async fn get_post_text(Path(id): Path<i64>) -> String {
+    format!("Benchmark Post #{}\n\nThis is a test post for benchmarking", id)
+}
+
 /// JSON request body parsing with validation
 #[rustapi_rs::post("/create-user")]
 #[rustapi_rs::tag("Benchmark")]
@@ -101,31 +144,51 @@ async fn create_user(ValidatedJson(body): ValidatedJson<CreateUser>) -> Json<Use
         id: 1,
         name: body.name,
         email: body.email,
-        created_at: "2024-01-01T00:00:00Z",
+        created_at: "2024-01-01T00:00:00Z".to_string(),
         is_active: true,
     })
 }
 
-/// Larger JSON response (10 users)
+/// Session-backed view counter - exercises the cookie jar + `Session` extractor
+#[rustapi_rs::get("/visits")]
+#[rustapi_rs::tag("Benchmark")]
+#[rustapi_rs::summary("Increment and return the session view count")]
+async fn visits(mut session: Session<VisitSession>) -> Json<VisitResponse> {
+    session.views += 1;
+    Json(VisitResponse {
+        views: session.views,
+    })
+}
+
+/// Authenticated-only route - rejects with 401 before the handler runs if unauthenticated
+#[rustapi_rs::get("/profile")]
+#[rustapi_rs::tag("Benchmark")]
+#[rustapi_rs::summary("Get the authenticated user's profile")]
+async fn profile(AuthorizedUser(user): AuthorizedUser<UserResponse>) -> Json<UserResponse> {
+    Json(user)
+}
+
+/// Larger JSON response (10 users per page, 100 total)
 #[rustapi_rs::get("/users-list")]
 #[rustapi_rs::tag("Benchmark")]
-#[rustapi_rs::summary("List users (10 items)")]
-async fn list_users() -> Json<UsersListResponse> {
-    let users: Vec<UserResponse> = (1..=10)
+#[rustapi_rs::summary("List users (paginated)")]
+async fn list_users(page: Page) -> Paginated<UserResponse> {
+    const TOTAL: usize = 100;
+
+    let start = page.offset();
+    let users: Vec<UserResponse> = (1..=page.per_page as i64)
+        .map(|i| start as i64 + i)
+        .filter(|&id| id as usize <= TOTAL)
         .map(|id| UserResponse {
             id,
             name: format!("User {}", id),
             email: format!("user{}@example.com", id),
-            created_at: "2024-01-01T00:00:00Z",
+            created_at: "2024-01-01T00:00:00Z".to_string(),
             is_active: id % 2 == 0,
         })
         .collect();
 
-    Json(UsersListResponse {
-        total: 100,
-        page: 1,
-        users,
-    })
+    Paginated::new(users, TOTAL, page)
 }
 
 // ============================================
@@ -137,12 +200,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Minimal output for benchmarks
     eprintln!("🚀 RustAPI Benchmark Server @ http://127.0.0.1:8080");
 
+    // Demo secret for signing session/auth cookies - load from the environment in production
     RustApi::new()
+        .cookie_secret(b"bench-server-demo-secret-do-not-use-in-prod")
+        .default_per_page(10)
         .mount_route(hello_route())
         .mount_route(json_hello_route())
+        .mount_route(get_current_user_route())
         .mount_route(get_user_route())
         .mount_route(get_post_route())
+        .mount_route(get_post_text_route())
         .mount_route(create_user_route())
+        .mount_route(visits_route())
+        .mount_route(profile_route())
         .mount_route(list_users_route())
         .run("127.0.0.1:8080")
         .await
@@ -0,0 +1,140 @@
+//! Full-pipeline benchmarks using RustAPI's in-process `TestClient`
+//!
+//! Unlike `json_bench.rs` (serde_json in isolation) and `routing_bench.rs`
+//! (matchit in isolation), this benchmarks the framework end-to-end:
+//! extractor parsing, `ValidatedJson`, handler dispatch, and response
+//! serialization, without binding a TCP socket.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rustapi_rs::prelude::*;
+
+#[derive(Serialize, Schema)]
+struct HelloResponse {
+    message: &'static str,
+}
+
+#[derive(Serialize, Deserialize, Schema)]
+struct UserResponse {
+    id: i64,
+    name: String,
+    email: String,
+    created_at: String,
+    is_active: bool,
+}
+
+#[derive(Deserialize, Validate, Schema)]
+struct CreateUser {
+    #[validate(length(min = 1, max = 100))]
+    name: String,
+    #[validate(email)]
+    email: String,
+}
+
+impl Check for CreateUser {
+    fn check(&self, _errors: &mut FieldErrors) {}
+}
+
+#[rustapi_rs::get("/")]
+async fn hello() -> &'static str {
+    "Hello, World!"
+}
+
+#[rustapi_rs::get("/json")]
+async fn json_hello() -> Json<HelloResponse> {
+    Json(HelloResponse {
+        message: "Hello, World!",
+    })
+}
+
+#[rustapi_rs::get("/users/{id}")]
+async fn get_user(Path(id): Path<i64>) -> Json<UserResponse> {
+    Json(UserResponse {
+        id,
+        name: format!("User {}", id),
+        email: format!("user{}@example.com", id),
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        is_active: true,
+    })
+}
+
+#[rustapi_rs::post("/create-user")]
+async fn create_user(ValidatedJson(body): ValidatedJson<CreateUser>) -> Json<UserResponse> {
+    Json(UserResponse {
+        id: 1,
+        name: body.name,
+        email: body.email,
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        is_active: true,
+    })
+}
+
+fn test_app() -> RustApi {
+    RustApi::new()
+        .mount_route(hello_route())
+        .mount_route(json_hello_route())
+        .mount_route(get_user_route())
+        .mount_route(create_user_route())
+}
+
+/// Benchmark the full pipeline for plain-text and JSON GET handlers
+fn bench_pipeline_get(c: &mut Criterion) {
+    let client = test_app().client();
+    let mut group = c.benchmark_group("pipeline_get");
+
+    group.bench_function("plain_text", |b| {
+        b.iter(|| client.get(black_box("/")).dispatch())
+    });
+
+    group.bench_function("json", |b| {
+        b.iter(|| client.get(black_box("/json")).dispatch())
+    });
+
+    group.bench_function("json_path_param", |b| {
+        b.iter(|| client.get(black_box("/users/123")).dispatch())
+    });
+
+    group.finish();
+}
+
+/// Benchmark the full pipeline for a validated JSON POST handler
+fn bench_pipeline_post(c: &mut Criterion) {
+    let client = test_app().client();
+    let mut group = c.benchmark_group("pipeline_post");
+
+    let body = r#"{"name": "John Doe", "email": "john@example.com"}"#;
+
+    group.bench_function("create_user", |b| {
+        b.iter(|| {
+            client
+                .post(black_box("/create-user"))
+                .body(black_box(body))
+                .dispatch()
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmark reading the response back out (status/header/json access)
+fn bench_pipeline_response(c: &mut Criterion) {
+    let client = test_app().client();
+    let mut group = c.benchmark_group("pipeline_response");
+
+    group.bench_function("json_decode", |b| {
+        b.iter(|| {
+            let response = client.get(black_box("/users/123")).dispatch();
+            black_box(response.json::<UserResponse>())
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_pipeline_get,
+    bench_pipeline_post,
+    bench_pipeline_response,
+);
+
+criterion_main!(benches);
@@ -4,6 +4,7 @@
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use matchit::Router;
+use rustapi_rs::prelude::*;
 
 /// Benchmark static route matching
 fn bench_static_routes(c: &mut Criterion) {
@@ -90,7 +91,7 @@ fn bench_router_scaling(c: &mut Criterion) {
         let mut router = Router::new();
 
         for i in 0..*route_count {
-            router.insert(&format!("/api/v1/resource{}", i), i).unwrap();
+            router.insert(format!("/api/v1/resource{}", i), i).unwrap();
         }
 
         // Always match the middle route
@@ -106,6 +107,57 @@ fn bench_router_scaling(c: &mut Criterion) {
     group.finish();
 }
 
+#[derive(Serialize, Schema)]
+struct RankedUser {
+    id: i64,
+}
+
+/// Rank 0: exact static segment, so it isn't shadowed by `/users/{id}` below.
+#[rustapi_rs::get("/users/me")]
+#[rustapi_rs::rank(0)]
+async fn current_user() -> Json<RankedUser> {
+    Json(RankedUser { id: 0 })
+}
+
+/// Rank 1 (the default for a named param): falls through from rank 0.
+#[rustapi_rs::get("/users/{id}")]
+async fn get_user(Path(id): Path<i64>) -> Json<RankedUser> {
+    Json(RankedUser { id })
+}
+
+/// Rank 100 (the default for a wildcard): falls through from rank 1.
+#[rustapi_rs::get("/users/{*rest}")]
+async fn users_fallback() -> Json<RankedUser> {
+    Json(RankedUser { id: -1 })
+}
+
+/// Benchmark ranked routing through the real dispatch path: overlapping
+/// static/dynamic/wildcard routes mounted on a `RustApi` and resolved by
+/// `Router::resolve`'s rank-bucket fallthrough, exercised via `TestClient`.
+fn bench_ranked_routes(c: &mut Criterion) {
+    let client = RustApi::new()
+        .mount_route(current_user_route())
+        .mount_route(get_user_route())
+        .mount_route(users_fallback_route())
+        .client();
+
+    let mut group = c.benchmark_group("ranked_routing");
+
+    group.bench_function("static_wins_over_dynamic", |b| {
+        b.iter(|| client.get(black_box("/users/me")).dispatch())
+    });
+
+    group.bench_function("dynamic_fallback", |b| {
+        b.iter(|| client.get(black_box("/users/123")).dispatch())
+    });
+
+    group.bench_function("wildcard_fallback", |b| {
+        b.iter(|| client.get(black_box("/users/123/extra/segments")).dispatch())
+    });
+
+    group.finish();
+}
+
 /// Benchmark wildcard routes
 fn bench_wildcard_routes(c: &mut Criterion) {
     let mut router = Router::new();
@@ -132,6 +184,7 @@ criterion_group!(
     bench_dynamic_routes,
     bench_router_scaling,
     bench_wildcard_routes,
+    bench_ranked_routes,
 );
 
 criterion_main!(benches);
@@ -0,0 +1,194 @@
+use crate::app_state::AppState;
+use crate::client::TestClient;
+use crate::cookie;
+use crate::method::Method;
+use crate::request::RequestParts;
+use crate::response::Response;
+use crate::routing::{RouteDef, Router};
+use crate::session_store::SessionStore;
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+const SESSION_COOKIE: &str = "session";
+
+/// The application: a router built up by chaining `mount_route`, plus the
+/// shared state (cookie-signing secret, session storage) every request gets
+/// access to through its extractors.
+#[derive(Default)]
+pub struct RustApi {
+    router: Router,
+    state: AppState,
+}
+
+impl RustApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mount_route(mut self, route: RouteDef) -> Self {
+        self.router.insert(route);
+        self
+    }
+
+    /// Sets the key used to sign and verify `AuthorizedUser`/`Session`
+    /// cookies. Required before mounting any route that uses them.
+    pub fn cookie_secret(mut self, secret: impl Into<Vec<u8>>) -> Self {
+        self.state.cookie_secret = secret.into();
+        self
+    }
+
+    /// Overrides the default in-memory [`SessionStore`].
+    pub fn session_store(mut self, store: impl SessionStore + 'static) -> Self {
+        self.state.session_store = Arc::new(store);
+        self
+    }
+
+    /// Overrides the `per_page` a [`Page`](crate::pagination::Page) extractor
+    /// falls back to when the request doesn't specify one.
+    pub fn default_per_page(mut self, n: usize) -> Self {
+        self.state.default_per_page = n;
+        self
+    }
+
+    /// Overrides the `per_page` cap a [`Page`](crate::pagination::Page)
+    /// extractor clamps requested values to.
+    pub fn max_per_page(mut self, n: usize) -> Self {
+        self.state.max_per_page = n;
+        self
+    }
+
+    /// Returns an in-process client that dispatches requests directly
+    /// through the mounted routes - no TCP socket involved - for fast tests
+    /// and full-pipeline benchmarks.
+    pub fn client(self) -> TestClient {
+        TestClient::new(self.router, Arc::new(self.state))
+    }
+
+    /// Binds `addr` and serves mounted routes until the process is killed.
+    pub async fn run(self, addr: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let listener = TcpListener::bind(addr).await?;
+        let router = Arc::new(self.router);
+        let state = Arc::new(self.state);
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let router = router.clone();
+            let state = state.clone();
+            tokio::spawn(async move {
+                let _ = serve_connection(stream, router, state).await;
+            });
+        }
+    }
+}
+
+pub(crate) async fn dispatch(
+    router: &Router,
+    method: Method,
+    path: &str,
+    query: &str,
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+    state: Arc<AppState>,
+) -> Response {
+    // GET handlers negotiate on what they produce (`Accept`); POST handlers
+    // negotiate on what they consume (`Content-Type`).
+    let negotiate_on = match method {
+        Method::Post => headers.get("content-type").cloned(),
+        Method::Get => headers.get("accept").cloned(),
+    };
+
+    match router.resolve(method, path, negotiate_on.as_deref()) {
+        Ok((route, params)) => {
+            let parts = RequestParts::new(method, path.to_string(), query, headers, body, params, state);
+            let session_cell = parts.session_cell.clone();
+            let cookie_secret = parts.state.cookie_secret.clone();
+            let session_store = parts.state.session_store.clone();
+            let mut response = (route.handler)(parts).await;
+
+            // A `Session` extractor writes its id/value here when dropped,
+            // after the handler (and thus the extractor) has run - persist
+            // it and re-sign the session cookie into the response.
+            if let Some((id, value)) = session_cell.take() {
+                session_store.save(&id, value);
+                let signed = cookie::sign(&cookie_secret, &id);
+                response.headers.push(("set-cookie".to_string(), cookie::set_cookie_header(SESSION_COOKIE, &signed)));
+            }
+
+            response
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+async fn serve_connection(
+    stream: tokio::net::TcpStream,
+    router: Arc<Router>,
+    state: Arc<AppState>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).await? == 0 {
+        return Ok(());
+    }
+
+    let mut parts = request_line.trim_end().split(' ');
+    let method_str = parts.next().unwrap_or("GET");
+    let target = parts.next().unwrap_or("/");
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let content_length: usize = headers.get("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+    let mut body = vec![0u8; content_length];
+    if content_length > 0 {
+        reader.read_exact(&mut body).await?;
+    }
+
+    let method = Method::parse(method_str).unwrap_or(Method::Get);
+    let response = dispatch(&router, method, path, query, headers, body, state).await;
+
+    let mut out = format!(
+        "HTTP/1.1 {} {}\r\ncontent-length: {}\r\n",
+        response.status,
+        status_text(response.status),
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        out.push_str(&format!("{name}: {value}\r\n"));
+    }
+    out.push_str("\r\n");
+
+    let stream = reader.into_inner();
+    let mut stream = stream;
+    stream.write_all(out.as_bytes()).await?;
+    stream.write_all(&response.body).await?;
+    stream.flush().await?;
+
+    Ok(())
+}
+
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        406 => "Not Acceptable",
+        _ => "Unknown",
+    }
+}
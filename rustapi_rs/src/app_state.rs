@@ -0,0 +1,24 @@
+use crate::pagination::{DEFAULT_PER_PAGE, MAX_PER_PAGE};
+use crate::session_store::{InMemorySessionStore, SessionStore};
+use std::sync::Arc;
+
+/// Shared, per-server configuration threaded through every request via
+/// [`RequestParts`](crate::request::RequestParts), set up through
+/// [`RustApi`](crate::RustApi)'s builder methods.
+pub struct AppState {
+    pub cookie_secret: Vec<u8>,
+    pub session_store: Arc<dyn SessionStore>,
+    pub default_per_page: usize,
+    pub max_per_page: usize,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        AppState {
+            cookie_secret: Vec::new(),
+            session_store: Arc::new(InMemorySessionStore::new()),
+            default_per_page: DEFAULT_PER_PAGE,
+            max_per_page: MAX_PER_PAGE,
+        }
+    }
+}
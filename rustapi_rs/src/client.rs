@@ -0,0 +1,121 @@
+use crate::app::dispatch;
+use crate::app_state::AppState;
+use crate::method::Method;
+use crate::routing::Router;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// An in-process test client: dispatches requests directly through the
+/// mounted routes (extractors, `ValidatedJson`, response serialization - the
+/// same path `RustApi::run` uses) without binding a TCP socket. Mirrors
+/// `Client::tracked(...).get("/").dispatch()` ergonomics.
+pub struct TestClient {
+    router: Router,
+    state: Arc<AppState>,
+}
+
+impl TestClient {
+    pub(crate) fn new(router: Router, state: Arc<AppState>) -> Self {
+        TestClient { router, state }
+    }
+
+    pub fn get(&self, path: &str) -> TestRequestBuilder<'_> {
+        TestRequestBuilder::new(self, Method::Get, path)
+    }
+
+    pub fn post(&self, path: &str) -> TestRequestBuilder<'_> {
+        TestRequestBuilder::new(self, Method::Post, path)
+    }
+}
+
+pub struct TestRequestBuilder<'a> {
+    client: &'a TestClient,
+    method: Method,
+    path: String,
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+impl<'a> TestRequestBuilder<'a> {
+    fn new(client: &'a TestClient, method: Method, path: &str) -> Self {
+        TestRequestBuilder {
+            client,
+            method,
+            path: path.to_string(),
+            headers: HashMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_ascii_lowercase(), value.to_string());
+        self
+    }
+
+    pub fn body(mut self, body: impl AsRef<[u8]>) -> Self {
+        self.body = body.as_ref().to_vec();
+        self
+    }
+
+    pub fn json(mut self, value: &impl Serialize) -> Self {
+        self.body = serde_json::to_vec(value).expect("request body is JSON-serializable");
+        self.headers
+            .entry("content-type".to_string())
+            .or_insert_with(|| "application/json".to_string());
+        self
+    }
+
+    pub fn dispatch(self) -> TestResponse {
+        let (path, query) = self.path.split_once('?').unwrap_or((self.path.as_str(), ""));
+        let response = futures::executor::block_on(dispatch(
+            &self.client.router,
+            self.method,
+            path,
+            query,
+            self.headers,
+            self.body,
+            self.client.state.clone(),
+        ));
+
+        TestResponse {
+            status: response.status,
+            headers: response.headers,
+            body: response.body,
+        }
+    }
+}
+
+/// The result of a [`TestRequestBuilder::dispatch`] call.
+pub struct TestResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl TestResponse {
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers
+            .iter()
+            .find(|(n, _)| n.to_ascii_lowercase() == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).expect("response body is valid JSON for the requested type")
+    }
+}
@@ -0,0 +1,80 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::HashMap;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Parses a raw `Cookie` request header into a name -> value map.
+pub fn parse_cookie_header(header: &str) -> HashMap<String, String> {
+    header
+        .split(';')
+        .filter_map(|pair| {
+            let pair = pair.trim();
+            let (name, value) = pair.split_once('=')?;
+            Some((name.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Signs `value` with an HMAC-SHA256 tag keyed by `secret`, producing a
+/// tamper-evident cookie value of the form `<value>.<base64 signature>`.
+///
+/// `value` must already be safe to embed in a single cookie-pair (no `;`,
+/// `,`, or other characters a `Cookie` header splits on) - callers signing
+/// arbitrary text (e.g. JSON) should base64/url-encode it first.
+pub fn sign(secret: &[u8], value: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts a key of any length");
+    mac.update(value.as_bytes());
+    let signature = mac.finalize().into_bytes();
+    format!("{}.{}", value, URL_SAFE_NO_PAD.encode(signature))
+}
+
+/// Verifies a cookie value produced by [`sign`], returning the original value
+/// if the signature matches the given secret.
+pub fn verify(secret: &[u8], signed: &str) -> Option<String> {
+    let (value, sig_b64) = signed.rsplit_once('.')?;
+    let signature = URL_SAFE_NO_PAD.decode(sig_b64).ok()?;
+
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts a key of any length");
+    mac.update(value.as_bytes());
+    mac.verify_slice(&signature).ok()?;
+
+    Some(value.to_string())
+}
+
+/// Builds a `Set-Cookie` header value for a session/auth cookie.
+pub fn set_cookie_header(name: &str, value: &str) -> String {
+    format!("{}={}; Path=/; HttpOnly; SameSite=Lax", name, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_and_verify_round_trip() {
+        let signed = sign(b"secret", "session-1");
+        assert_eq!(verify(b"secret", &signed).as_deref(), Some("session-1"));
+    }
+
+    #[test]
+    fn verify_rejects_tampering() {
+        let signed = sign(b"secret", "session-1");
+        let tampered = signed.replace("session-1", "session-2");
+        assert_eq!(verify(b"secret", &tampered), None);
+    }
+
+    #[test]
+    fn verify_rejects_wrong_secret() {
+        let signed = sign(b"secret", "session-1");
+        assert_eq!(verify(b"other-secret", &signed), None);
+    }
+
+    #[test]
+    fn parses_multiple_cookies() {
+        let cookies = parse_cookie_header("sid=abc; theme=dark");
+        assert_eq!(cookies.get("sid").map(String::as_str), Some("abc"));
+        assert_eq!(cookies.get("theme").map(String::as_str), Some("dark"));
+    }
+}
@@ -0,0 +1,53 @@
+use crate::cookie;
+use crate::extract::FromRequest;
+use crate::request::RequestParts;
+use crate::response::Response;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+const AUTH_COOKIE: &str = "auth";
+
+/// A signed-cookie-backed identity extractor: `AuthorizedUser<T>` extracts
+/// `T` from the request's auth cookie, rejecting the request with `401` if
+/// it's missing or the signature doesn't check out.
+///
+/// [`cookie_value`] builds the `Set-Cookie` header that logs a user in.
+pub struct AuthorizedUser<T>(pub T);
+
+/// Builds the signed `Set-Cookie` header value that logs `user` in.
+///
+/// The user is serialized to JSON and base64/url-encoded before signing -
+/// embedding raw JSON in a cookie value would let any `;` or `,` in the
+/// payload (e.g. a name like `Smith; Evil`) get split apart by cookie-pair
+/// parsing on the next request, breaking the signature check.
+pub fn cookie_value<T: Serialize>(secret: &[u8], user: &T) -> String {
+    let json = serde_json::to_string(user).expect("user is JSON-serializable");
+    let encoded = URL_SAFE_NO_PAD.encode(json.as_bytes());
+    cookie::set_cookie_header(AUTH_COOKIE, &cookie::sign(secret, &encoded))
+}
+
+impl<T> FromRequest for AuthorizedUser<T>
+where
+    T: DeserializeOwned + Send,
+{
+    async fn from_request(parts: &mut RequestParts) -> Result<Self, Response> {
+        let signed = parts
+            .cookies
+            .get(AUTH_COOKIE)
+            .ok_or_else(|| Response::text(401, "missing auth cookie"))?;
+
+        let encoded = cookie::verify(&parts.state.cookie_secret, signed)
+            .ok_or_else(|| Response::text(401, "invalid auth cookie"))?;
+
+        let json = URL_SAFE_NO_PAD
+            .decode(&encoded)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(|| Response::text(401, "invalid auth cookie"))?;
+
+        let user = serde_json::from_str(&json).map_err(|_| Response::text(401, "invalid auth cookie"))?;
+
+        Ok(AuthorizedUser(user))
+    }
+}
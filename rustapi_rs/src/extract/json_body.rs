@@ -0,0 +1,15 @@
+use crate::extract::FromRequest;
+use crate::request::RequestParts;
+use crate::response::{Json, Response};
+use serde::de::DeserializeOwned;
+
+impl<T> FromRequest for Json<T>
+where
+    T: DeserializeOwned + Send,
+{
+    async fn from_request(parts: &mut RequestParts) -> Result<Self, Response> {
+        serde_json::from_slice(&parts.body)
+            .map(Json)
+            .map_err(|e| Response::text(400, format!("invalid JSON body: {e}")))
+    }
+}
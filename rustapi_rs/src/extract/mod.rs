@@ -0,0 +1,19 @@
+mod auth;
+mod json_body;
+mod path;
+pub(crate) mod session;
+mod validated_json;
+
+pub use auth::{cookie_value, AuthorizedUser};
+pub use path::Path;
+pub use session::Session;
+pub use validated_json::ValidatedJson;
+
+use crate::request::RequestParts;
+use crate::response::Response;
+
+/// Extracts `Self` from a request, short-circuiting the handler with a
+/// pre-built [`Response`] (400/...) on failure.
+pub trait FromRequest: Sized {
+    fn from_request(parts: &mut RequestParts) -> impl std::future::Future<Output = Result<Self, Response>> + Send;
+}
@@ -0,0 +1,29 @@
+use crate::extract::FromRequest;
+use crate::request::RequestParts;
+use crate::response::Response;
+use std::str::FromStr;
+
+/// Extracts the route's single path parameter, parsed via `FromStr`.
+#[derive(Debug, Clone, Copy)]
+pub struct Path<T>(pub T);
+
+impl<T> FromRequest for Path<T>
+where
+    T: FromStr + Send,
+{
+    async fn from_request(parts: &mut RequestParts) -> Result<Self, Response> {
+        // A route pattern is expected to carry exactly one named segment for
+        // `Path<T>` to bind unambiguously; `parts.params` is a `HashMap`, so
+        // picking an arbitrary entry when there's more than one (or none)
+        // would silently bind the wrong value instead of failing loudly.
+        if parts.params.len() != 1 {
+            return Err(Response::text(400, "route does not have exactly one path parameter"));
+        }
+
+        let raw = parts.params.values().next().expect("checked len() == 1 above");
+
+        raw.parse::<T>()
+            .map(Path)
+            .map_err(|_| Response::text(400, "invalid path parameter"))
+    }
+}
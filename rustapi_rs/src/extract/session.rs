@@ -0,0 +1,90 @@
+use crate::cookie;
+use crate::extract::FromRequest;
+use crate::request::RequestParts;
+use crate::response::Response;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+
+const SESSION_COOKIE: &str = "session";
+
+/// A session value persisted to the server's [`SessionStore`](crate::session_store::SessionStore).
+///
+/// Extracting `Session<T>` loads `T` from the store (creating a fresh session
+/// if the request didn't carry one), and deref gives mutable access to it.
+/// Whatever the handler leaves it holding when it's dropped is written back
+/// to the store and re-signed into the response's `Set-Cookie` header - there
+/// is no explicit "save" call.
+pub struct Session<T: Serialize> {
+    id: String,
+    value: T,
+    cell: SessionCell,
+}
+
+impl<T: Serialize> Deref for Session<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Serialize> DerefMut for Session<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Serialize> Drop for Session<T> {
+    fn drop(&mut self) {
+        let value = serde_json::to_value(&self.value).expect("session value is JSON-serializable");
+        self.cell.set(self.id.clone(), value);
+    }
+}
+
+impl<T> FromRequest for Session<T>
+where
+    T: Serialize + DeserializeOwned + Default + Send,
+{
+    async fn from_request(parts: &mut RequestParts) -> Result<Self, Response> {
+        let signed = parts.cookies.get(SESSION_COOKIE).cloned();
+        let existing_id = signed.and_then(|signed| cookie::verify(&parts.state.cookie_secret, &signed));
+
+        let (id, value) = match existing_id {
+            Some(id) => match parts.state.session_store.load(&id) {
+                Some(raw) => {
+                    let value = serde_json::from_value(raw).unwrap_or_default();
+                    (id, value)
+                }
+                None => (parts.state.session_store.create(), T::default()),
+            },
+            None => (parts.state.session_store.create(), T::default()),
+        };
+
+        Ok(Session {
+            id,
+            value,
+            cell: parts.session_cell.clone(),
+        })
+    }
+}
+
+/// A pending `(session id, value)` write, handed from a dropped [`Session`]
+/// to the dispatcher so it can persist it and sign a fresh session cookie
+/// into the response after the handler has already returned.
+#[derive(Clone, Default)]
+pub struct SessionCell(Arc<Mutex<Option<(String, serde_json::Value)>>>);
+
+impl SessionCell {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn set(&self, id: String, value: serde_json::Value) {
+        *self.0.lock().unwrap() = Some((id, value));
+    }
+
+    pub fn take(&self) -> Option<(String, serde_json::Value)> {
+        self.0.lock().unwrap().take()
+    }
+}
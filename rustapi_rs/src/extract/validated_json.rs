@@ -0,0 +1,30 @@
+use crate::extract::FromRequest;
+use crate::request::RequestParts;
+use crate::response::Response;
+use crate::validate::{Check, Validate};
+use serde::de::DeserializeOwned;
+
+/// Parses a JSON body, then runs the derived `Validate` pass followed by any
+/// hand-written `Check` pass, short-circuiting with a `422` listing every
+/// violation from both if any field fails.
+#[derive(Debug, Clone)]
+pub struct ValidatedJson<T>(pub T);
+
+impl<T> FromRequest for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate + Check + Send,
+{
+    async fn from_request(parts: &mut RequestParts) -> Result<Self, Response> {
+        let value: T = serde_json::from_slice(&parts.body)
+            .map_err(|e| Response::text(400, format!("invalid JSON body: {e}")))?;
+
+        let mut errors = value.validate();
+        value.check(&mut errors);
+
+        if errors.is_empty() {
+            Ok(ValidatedJson(value))
+        } else {
+            Err(errors.into_response())
+        }
+    }
+}
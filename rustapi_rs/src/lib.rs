@@ -0,0 +1,26 @@
+//! A small, benchmark-focused web framework: `matchit` routing, serde-based
+//! extractors, and an in-process test client, built to be measured against
+//! `actix-web` rather than to compete with it on features.
+
+mod app;
+mod app_state;
+mod client;
+pub mod cookie;
+pub mod extract;
+mod method;
+pub mod negotiate;
+pub mod pagination;
+pub mod prelude;
+pub mod request;
+pub mod response;
+pub mod routing;
+pub mod schema;
+pub mod session_store;
+pub mod validate;
+
+pub use app::RustApi;
+pub use app_state::AppState;
+pub use client::{TestClient, TestRequestBuilder, TestResponse};
+pub use method::Method;
+
+pub use rustapi_rs_macros::{format, get, post, rank, summary, tag, Schema, Validate};
@@ -0,0 +1,173 @@
+use crate::routing::RouteDef;
+
+#[derive(Debug, Clone, PartialEq)]
+struct MediaRange {
+    kind: String,
+    subtype: String,
+    q: f32,
+}
+
+/// Parses an `Accept` (or `Content-Type`) header into its media ranges with
+/// q-values, defaulting missing q-values to `1.0`. Malformed entries are
+/// skipped.
+fn parse_accept(header: &str) -> Vec<MediaRange> {
+    header
+        .split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            if part.is_empty() {
+                return None;
+            }
+            let mut segments = part.split(';');
+            let media = segments.next()?.trim();
+            let (kind, subtype) = media.split_once('/')?;
+
+            let mut q = 1.0f32;
+            for param in segments {
+                let param = param.trim();
+                if let Some(value) = param.strip_prefix("q=") {
+                    q = value.trim().parse().unwrap_or(1.0);
+                }
+            }
+
+            Some(MediaRange {
+                kind: kind.trim().to_string(),
+                subtype: subtype.trim().to_string(),
+                q,
+            })
+        })
+        .collect()
+}
+
+/// Score a candidate `content-type` (e.g. `"application/json"`) against a
+/// single media range. Higher is a better (more specific) match; `None` means
+/// the range does not accept this content type at all. Weights are on a
+/// 1..=3 scale so that a route with no declared `format` (weight `0`, see
+/// [`select`]) always loses to one with an explicit, matching format.
+fn score_against(content_type: &str, range: &MediaRange) -> Option<(u8, f32)> {
+    if range.q <= 0.0 {
+        return None;
+    }
+    let (ckind, csub) = content_type.split_once('/')?;
+
+    if range.kind == ckind && range.subtype == csub {
+        Some((3, range.q))
+    } else if range.kind == ckind && range.subtype == "*" {
+        Some((2, range.q))
+    } else if range.kind == "*" && range.subtype == "*" {
+        Some((1, range.q))
+    } else {
+        None
+    }
+}
+
+/// Picks the best-matching route among `candidates` (all already filtered to
+/// the same method+path) for the given negotiation header - `Accept` for GET,
+/// `Content-Type` for POST (see `app::dispatch`). Routes without a `format`
+/// constraint always match, at the lowest specificity. Returns `None` if
+/// `header` is present and non-empty but matches no candidate - callers
+/// should respond `406 Not Acceptable` in that case.
+pub fn select<'a>(candidates: &[&'a RouteDef], header: Option<&str>) -> Option<&'a RouteDef> {
+    if candidates.len() == 1 && candidates[0].format.is_none() {
+        return Some(candidates[0]);
+    }
+
+    let ranges = match header {
+        Some(header) if !header.trim().is_empty() => parse_accept(header),
+        _ => vec![MediaRange {
+            kind: "*".to_string(),
+            subtype: "*".to_string(),
+            q: 1.0,
+        }],
+    };
+
+    if ranges.is_empty() {
+        return None;
+    }
+
+    let mut best: Option<(&'a RouteDef, (u8, f32))> = None;
+    for &candidate in candidates {
+        let candidate_score = match candidate.format {
+            Some(format) => ranges
+                .iter()
+                .filter_map(|r| score_against(format, r))
+                .max_by(|a, b| a.partial_cmp(b).unwrap()),
+            // No declared format: matches anything, but ranked below any
+            // explicit format match so explicit negotiation wins ties.
+            None => ranges.iter().find(|r| r.q > 0.0).map(|r| (0u8, r.q)),
+        };
+
+        if let Some(score) = candidate_score {
+            let better = match &best {
+                Some((_, best_score)) => score > *best_score,
+                None => true,
+            };
+            if better {
+                best = Some((candidate, score));
+            }
+        }
+    }
+
+    best.map(|(route, _)| route)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::method::Method;
+    use crate::request::RequestParts;
+    use crate::response::IntoResponse;
+    use std::sync::Arc;
+
+    fn make_route(format: Option<&'static str>) -> RouteDef {
+        RouteDef {
+            method: Method::Get,
+            pattern: "/posts/{id}",
+            rank: None,
+            format,
+            tag: None,
+            summary: None,
+            handler: Arc::new(move |_parts: RequestParts| {
+                Box::pin(async move { "ok".into_response() })
+            }),
+        }
+    }
+
+    #[test]
+    fn exact_match_wins_over_wildcard() {
+        let json_route = make_route(Some("application/json"));
+        let text_route = make_route(Some("text/plain"));
+        let candidates = [&json_route, &text_route];
+
+        let picked = select(&candidates, Some("text/plain;q=0.5, application/json;q=0.9"));
+        assert!(std::ptr::eq(picked.unwrap(), &json_route));
+    }
+
+    #[test]
+    fn q_value_breaks_tie_between_exact_matches() {
+        let json_route = make_route(Some("application/json"));
+        let text_route = make_route(Some("text/plain"));
+        let candidates = [&json_route, &text_route];
+
+        let picked = select(&candidates, Some("application/json;q=0.2, text/plain;q=0.9"));
+        assert!(std::ptr::eq(picked.unwrap(), &text_route));
+    }
+
+    #[test]
+    fn no_matching_range_is_not_acceptable() {
+        let json_route = make_route(Some("application/json"));
+        let text_route = make_route(Some("text/plain"));
+        let candidates = [&json_route, &text_route];
+
+        let picked = select(&candidates, Some("text/html"));
+        assert!(picked.is_none());
+    }
+
+    #[test]
+    fn missing_accept_header_still_picks_a_route() {
+        let json_route = make_route(Some("application/json"));
+        let candidates = [&json_route];
+
+        assert!(select(&candidates, None).is_some());
+    }
+}
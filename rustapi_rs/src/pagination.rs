@@ -0,0 +1,109 @@
+use crate::extract::FromRequest;
+use crate::request::RequestParts;
+use crate::response::{IntoResponse, Response};
+use crate::schema::{Schema, SchemaNode};
+use serde::Serialize;
+
+/// Falls back to these when the server hasn't configured its own via
+/// `RustApi::default_per_page`/`max_per_page`.
+pub const DEFAULT_PER_PAGE: usize = 20;
+pub const MAX_PER_PAGE: usize = 100;
+
+/// Parsed `?page=&per_page=` query parameters, clamped against the server's
+/// configured `default_per_page`/`max_per_page` (see
+/// [`RustApi::default_per_page`](crate::RustApi::default_per_page) /
+/// [`max_per_page`](crate::RustApi::max_per_page)).
+#[derive(Debug, Clone, Copy)]
+pub struct Page {
+    pub page: usize,
+    pub per_page: usize,
+}
+
+impl Page {
+    pub fn offset(&self) -> usize {
+        self.page.saturating_sub(1).saturating_mul(self.per_page)
+    }
+
+    pub fn has_more(&self, total: usize) -> bool {
+        self.offset() + self.per_page < total
+    }
+}
+
+impl FromRequest for Page {
+    async fn from_request(parts: &mut RequestParts) -> Result<Self, Response> {
+        let page = parts
+            .query
+            .get("page")
+            .and_then(|v| v.parse().ok())
+            .filter(|&p: &usize| p >= 1)
+            .unwrap_or(1);
+
+        let max_per_page = parts.state.max_per_page;
+        let per_page = parts
+            .query
+            .get("per_page")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(parts.state.default_per_page)
+            .clamp(1, max_per_page);
+
+        Ok(Page { page, per_page })
+    }
+}
+
+/// A page of `T`s, together with enough metadata (`total`, `has_more`) for a
+/// client to keep paging without a separate count request.
+#[derive(Debug, Clone, Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+    pub page: usize,
+    pub per_page: usize,
+    pub has_more: bool,
+}
+
+impl<T> Paginated<T> {
+    pub fn new(items: Vec<T>, total: usize, page: Page) -> Self {
+        Paginated {
+            items,
+            total,
+            page: page.page,
+            per_page: page.per_page,
+            has_more: page.has_more(total),
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for Paginated<T> {
+    fn into_response(self) -> Response {
+        Response::json_value(200, &self)
+    }
+}
+
+impl<T: Schema> Schema for Paginated<T> {
+    fn schema() -> SchemaNode {
+        SchemaNode::object(vec![
+            ("items", SchemaNode::array(T::schema())),
+            ("total", usize::schema()),
+            ("page", usize::schema()),
+            ("per_page", usize::schema()),
+            ("has_more", bool::schema()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_more_false_on_last_partial_page() {
+        let page = Page { page: 5, per_page: 20 };
+        assert!(!page.has_more(99));
+    }
+
+    #[test]
+    fn offset_clamped_per_page_respects_max() {
+        let page = Page { page: 2, per_page: 20 };
+        assert_eq!(page.offset(), 20);
+    }
+}
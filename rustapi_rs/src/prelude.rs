@@ -0,0 +1,11 @@
+//! `use rustapi_rs::prelude::*;` pulls in everything a typical handler file needs.
+
+pub use crate::extract::{AuthorizedUser, Path, Session, ValidatedJson};
+pub use crate::pagination::{Page, Paginated};
+pub use crate::response::{IntoResponse, Json, Response};
+pub use crate::schema::Schema;
+pub use crate::validate::{assert_length, Check, FieldErrors, Validate};
+pub use crate::{Method, RustApi};
+pub use rustapi_rs_macros::{Schema, Validate};
+
+pub use serde::{Deserialize, Serialize};
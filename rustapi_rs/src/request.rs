@@ -0,0 +1,110 @@
+use crate::app_state::AppState;
+use crate::cookie;
+use crate::extract::session::SessionCell;
+use crate::method::Method;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Everything a handler's extractors need: the parsed request. Built by the
+/// router before a route's handler runs.
+pub struct RequestParts {
+    pub method: Method,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    pub params: HashMap<String, String>,
+    pub body: Vec<u8>,
+    pub cookies: HashMap<String, String>,
+    pub state: Arc<AppState>,
+    pub(crate) session_cell: SessionCell,
+}
+
+impl RequestParts {
+    pub fn new(
+        method: Method,
+        path: String,
+        query_string: &str,
+        headers: HashMap<String, String>,
+        body: Vec<u8>,
+        params: HashMap<String, String>,
+        state: Arc<AppState>,
+    ) -> Self {
+        let cookies = headers
+            .get("cookie")
+            .map(|header| cookie::parse_cookie_header(header))
+            .unwrap_or_default();
+
+        RequestParts {
+            method,
+            path,
+            query: parse_query_string(query_string),
+            headers,
+            params,
+            body,
+            cookies,
+            state,
+            session_cell: SessionCell::new(),
+        }
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+}
+
+pub fn parse_query_string(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|s| !s.is_empty())
+        .map(|pair| {
+            let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+            (urldecode(k), urldecode(v))
+        })
+        .collect()
+}
+
+/// Percent-decodes `s`, collecting raw bytes first and parsing the whole
+/// result as UTF-8 at the end - decoding byte-by-byte with `as char` would
+/// mangle any encoded multi-byte sequence (e.g. `%C3%A9` for "é").
+fn urldecode(s: &str) -> String {
+    let mut out = Vec::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '+' => out.push(b' '),
+            '%' => {
+                let hi = chars.next();
+                let lo = chars.next();
+                if let (Some(hi), Some(lo)) = (hi, lo) {
+                    if let Ok(byte) = u8::from_str_radix(&format!("{hi}{lo}"), 16) {
+                        out.push(byte);
+                        continue;
+                    }
+                }
+                out.push(b'%');
+            }
+            other => {
+                let mut buf = [0u8; 4];
+                out.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_multibyte_utf8_percent_sequences() {
+        let query = parse_query_string("name=%C3%A9");
+        assert_eq!(query.get("name").map(String::as_str), Some("é"));
+    }
+
+    #[test]
+    fn decodes_plus_as_space() {
+        let query = parse_query_string("q=a+b");
+        assert_eq!(query.get("q").map(String::as_str), Some("a b"));
+    }
+}
@@ -0,0 +1,76 @@
+use serde::Serialize;
+
+/// An outgoing HTTP response built by a handler (or by the framework itself
+/// for errors like 404/406/422).
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn new(status: u16) -> Self {
+        Response {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    pub fn text(status: u16, body: impl Into<String>) -> Self {
+        Response {
+            status,
+            headers: vec![("content-type".to_string(), "text/plain".to_string())],
+            body: body.into().into_bytes(),
+        }
+    }
+
+    pub fn json_value(status: u16, value: &impl Serialize) -> Self {
+        let body = serde_json::to_vec(value).expect("response value is JSON-serializable");
+        Response {
+            status,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body,
+        }
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Converts a handler's return value into a [`Response`].
+pub trait IntoResponse {
+    fn into_response(self) -> Response;
+}
+
+impl IntoResponse for Response {
+    fn into_response(self) -> Response {
+        self
+    }
+}
+
+impl IntoResponse for &'static str {
+    fn into_response(self) -> Response {
+        Response::text(200, self)
+    }
+}
+
+impl IntoResponse for String {
+    fn into_response(self) -> Response {
+        Response::text(200, self)
+    }
+}
+
+/// A JSON request/response wrapper, usable both as an extractor (`T: Deserialize`)
+/// and as a handler return type (`T: Serialize`).
+#[derive(Debug, Clone)]
+pub struct Json<T>(pub T);
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        Response::json_value(200, &self.0)
+    }
+}
@@ -0,0 +1,157 @@
+use crate::method::Method;
+use crate::negotiate;
+use crate::request::RequestParts;
+use crate::response::Response;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+pub type HandlerFn =
+    Arc<dyn Fn(RequestParts) -> Pin<Box<dyn Future<Output = Response> + Send>> + Send + Sync>;
+
+/// A single registered route, produced by the `#[rustapi_rs::get]`/`post`
+/// attribute macros and consumed by [`RustApi::mount_route`](crate::RustApi::mount_route).
+#[derive(Clone)]
+pub struct RouteDef {
+    pub method: Method,
+    pub pattern: &'static str,
+    /// `None` means "derive from path specificity" - see [`auto_rank`].
+    /// Lower numbers are tried first and win ties.
+    pub rank: Option<i32>,
+    /// The content type this route produces (or, for POST, expects); `None`
+    /// means it isn't part of content negotiation and always matches.
+    pub format: Option<&'static str>,
+    pub tag: Option<&'static str>,
+    pub summary: Option<&'static str>,
+    pub handler: HandlerFn,
+}
+
+/// Derives a route's default rank from its path's specificity: an exact
+/// static path wins over one with a named parameter, which wins over a
+/// trailing wildcard.
+fn auto_rank(pattern: &str) -> i32 {
+    if pattern.contains("{*") {
+        100
+    } else if pattern.contains('{') {
+        50
+    } else {
+        0
+    }
+}
+
+#[derive(Default)]
+struct RouteGroup {
+    routes: Vec<RouteDef>,
+}
+
+#[derive(Default)]
+struct RankBucket {
+    matcher: matchit::Router<usize>,
+    groups: Vec<RouteGroup>,
+    pattern_index: HashMap<String, usize>,
+}
+
+/// Why a request couldn't be dispatched to a handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchError {
+    NotFound,
+    NotAcceptable,
+}
+
+impl DispatchError {
+    pub fn into_response(self) -> Response {
+        match self {
+            DispatchError::NotFound => Response::text(404, "Not Found"),
+            DispatchError::NotAcceptable => Response::text(406, "Not Acceptable"),
+        }
+    }
+}
+
+/// Rank-bucketed router: each rank gets its own `matchit::Router`, tried in
+/// ascending order, so conflicting patterns (`/users/me` vs `/users/{id}`)
+/// can coexist as long as they don't collide within the same rank.
+#[derive(Default)]
+pub struct Router {
+    buckets: BTreeMap<i32, RankBucket>,
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a route, panicking only on a genuine conflict: two routes
+    /// with the identical path *and* rank that `matchit` cannot distinguish
+    /// (e.g. `/users/{id}` and `/users/{name}` at the same rank). Two routes
+    /// with the same path and rank but different methods/formats are fine -
+    /// they're grouped together and disambiguated at dispatch time.
+    pub fn insert(&mut self, route: RouteDef) {
+        let rank = route.rank.unwrap_or_else(|| auto_rank(route.pattern));
+        let pattern = route.pattern.to_string();
+        let bucket = self.buckets.entry(rank).or_default();
+
+        if let Some(&idx) = bucket.pattern_index.get(&pattern) {
+            bucket.groups[idx].routes.push(route);
+            return;
+        }
+
+        let idx = bucket.groups.len();
+        bucket
+            .matcher
+            .insert(pattern.clone(), idx)
+            .unwrap_or_else(|e| {
+                panic!(
+                    "route `{}` conflicts with an existing route at rank {}: {}",
+                    pattern, rank, e
+                )
+            });
+        bucket.pattern_index.insert(pattern, idx);
+        bucket.groups.push(RouteGroup { routes: vec![route] });
+    }
+
+    /// Resolves `method path` to the route that should handle it, trying
+    /// rank buckets from lowest to highest and performing content
+    /// negotiation within whichever bucket's pattern matches first.
+    ///
+    /// A bucket whose pattern matches the path but has no route for this
+    /// method doesn't stop the search: a lower-ranked, more specific pattern
+    /// (e.g. `/users/me` at rank 0) shouldn't swallow a request meant for a
+    /// higher-ranked, more general one (e.g. `/users/{id}` at rank 1) just
+    /// because the method doesn't match at the first bucket that matched the
+    /// path - so a method mismatch falls through to the next bucket instead
+    /// of failing the whole lookup.
+    pub fn resolve(
+        &self,
+        method: Method,
+        path: &str,
+        accept: Option<&str>,
+    ) -> Result<(&RouteDef, HashMap<String, String>), DispatchError> {
+        for bucket in self.buckets.values() {
+            let Ok(matched) = bucket.matcher.at(path) else {
+                continue;
+            };
+
+            let params = matched
+                .params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+
+            let group = &bucket.groups[*matched.value];
+            let candidates: Vec<&RouteDef> =
+                group.routes.iter().filter(|r| r.method == method).collect();
+
+            if candidates.is_empty() {
+                continue;
+            }
+
+            return match negotiate::select(&candidates, accept) {
+                Some(route) => Ok((route, params)),
+                None => Err(DispatchError::NotAcceptable),
+            };
+        }
+
+        Err(DispatchError::NotFound)
+    }
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+
+/// A minimal JSON-schema node, enough to describe the bench server's request
+/// and response shapes for OpenAPI-style output.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum SchemaNode {
+    Type {
+        #[serde(rename = "type")]
+        kind: &'static str,
+    },
+    Array {
+        #[serde(rename = "type")]
+        kind: &'static str,
+        items: Box<SchemaNode>,
+    },
+    Object {
+        #[serde(rename = "type")]
+        kind: &'static str,
+        properties: Vec<(&'static str, SchemaNode)>,
+    },
+}
+
+impl SchemaNode {
+    pub fn scalar(kind: &'static str) -> Self {
+        SchemaNode::Type { kind }
+    }
+
+    pub fn array(items: SchemaNode) -> Self {
+        SchemaNode::Array {
+            kind: "array",
+            items: Box::new(items),
+        }
+    }
+
+    pub fn object(properties: Vec<(&'static str, SchemaNode)>) -> Self {
+        SchemaNode::Object {
+            kind: "object",
+            properties,
+        }
+    }
+}
+
+/// Derived via `#[derive(Schema)]`, or implemented by hand for framework
+/// types (`Page`, `Paginated<T>`) so handlers using them get documentation
+/// for free.
+pub trait Schema {
+    fn schema() -> SchemaNode;
+}
+
+macro_rules! impl_scalar_schema {
+    ($ty:ty, $kind:literal) => {
+        impl Schema for $ty {
+            fn schema() -> SchemaNode {
+                SchemaNode::scalar($kind)
+            }
+        }
+    };
+}
+
+impl_scalar_schema!(String, "string");
+impl_scalar_schema!(bool, "boolean");
+impl_scalar_schema!(i8, "integer");
+impl_scalar_schema!(i16, "integer");
+impl_scalar_schema!(i32, "integer");
+impl_scalar_schema!(i64, "integer");
+impl_scalar_schema!(u8, "integer");
+impl_scalar_schema!(u16, "integer");
+impl_scalar_schema!(u32, "integer");
+impl_scalar_schema!(u64, "integer");
+impl_scalar_schema!(usize, "integer");
+impl_scalar_schema!(f32, "number");
+impl_scalar_schema!(f64, "number");
+
+impl Schema for &'static str {
+    fn schema() -> SchemaNode {
+        SchemaNode::scalar("string")
+    }
+}
+
+impl<T: Schema> Schema for Vec<T> {
+    fn schema() -> SchemaNode {
+        SchemaNode::array(T::schema())
+    }
+}
+
+impl<T: Schema> Schema for Option<T> {
+    fn schema() -> SchemaNode {
+        T::schema()
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Backing storage for [`Session`](crate::extract::Session) data, keyed by an
+/// opaque session id carried in a signed cookie.
+pub trait SessionStore: Send + Sync {
+    /// Allocates a new session id with an empty value.
+    fn create(&self) -> String;
+    /// Loads the stored value for `id`, if any.
+    fn load(&self, id: &str) -> Option<serde_json::Value>;
+    /// Persists `value` under `id`, creating the entry if it doesn't exist.
+    fn save(&self, id: &str, value: serde_json::Value);
+}
+
+/// A [`SessionStore`] backed by an in-process `HashMap`. Sessions don't
+/// survive a restart - fine for a benchmark server, not for production use.
+#[derive(Default)]
+pub struct InMemorySessionStore {
+    sessions: Mutex<HashMap<String, serde_json::Value>>,
+    next_id: AtomicU64,
+}
+
+impl InMemorySessionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SessionStore for InMemorySessionStore {
+    fn create(&self) -> String {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let id = format!("sess-{id}");
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(id.clone(), serde_json::Value::Null);
+        id
+    }
+
+    fn load(&self, id: &str) -> Option<serde_json::Value> {
+        self.sessions.lock().unwrap().get(id).cloned()
+    }
+
+    fn save(&self, id: &str, value: serde_json::Value) {
+        self.sessions.lock().unwrap().insert(id.to_string(), value);
+    }
+}
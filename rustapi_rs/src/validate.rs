@@ -0,0 +1,120 @@
+use crate::response::Response;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// A machine-readable `{ field: [messages] }` map of validation violations,
+/// collected from the derived `Validate` pass so callers see every problem at
+/// once, not just the first.
+#[derive(Debug, Default, Serialize)]
+pub struct FieldErrors {
+    errors: HashMap<String, Vec<String>>,
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.entry(field.to_string()).or_default().push(message.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn merge(&mut self, other: FieldErrors) {
+        for (field, messages) in other.errors {
+            self.errors.entry(field).or_default().extend(messages);
+        }
+    }
+
+    /// Builds the `422 Unprocessable Entity` response body for these errors.
+    pub fn into_response(self) -> Response {
+        let body = ValidationErrorBody {
+            message: "Validation failed",
+            errors: &self.errors,
+        };
+        Response::json_value(422, &body)
+    }
+}
+
+#[derive(Serialize)]
+struct ValidationErrorBody<'a> {
+    message: &'a str,
+    errors: &'a HashMap<String, Vec<String>>,
+}
+
+/// Per-field attribute validation, implemented by `#[derive(Validate)]` from
+/// `#[validate(length(...))]` / `#[validate(email)]` attributes.
+pub trait Validate {
+    fn validate(&self) -> FieldErrors;
+}
+
+/// Cross-field/whole-value validation that doesn't fit a per-field
+/// `#[validate(...)]` attribute - implemented by hand, run after the derived
+/// `Validate` pass by extractors like `ValidatedJson`.
+pub trait Check {
+    fn check(&self, errors: &mut FieldErrors);
+}
+
+pub fn check_length(errors: &mut FieldErrors, field: &str, value: &str, min: Option<usize>, max: Option<usize>) {
+    let len = value.chars().count();
+    if let Some(min) = min {
+        if len < min {
+            errors.add(field, format!("{field} must be at least {min} characters"));
+        }
+    }
+    if let Some(max) = max {
+        if len > max {
+            errors.add(field, format!("{field} must be at most {max} characters"));
+        }
+    }
+}
+
+/// Records an error against `field` unless `value`'s length in chars falls
+/// within `[min, max]` - the `Check`-impl counterpart to the derived
+/// `#[validate(length(...))]` attribute, for rules written by hand.
+pub fn assert_length(errors: &mut FieldErrors, field: &str, value: &str, min: usize, max: usize, message: &str) {
+    let len = value.chars().count();
+    if len < min || len > max {
+        errors.add(field, message);
+    }
+}
+
+pub fn check_email(errors: &mut FieldErrors, field: &str, value: &str) {
+    let valid = value
+        .split_once('@')
+        .map(|(local, domain)| !local.is_empty() && domain.contains('.') && !domain.starts_with('.'))
+        .unwrap_or(false);
+    if !valid {
+        errors.add(field, format!("{field} must be a valid email address"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_length_flags_both_bounds() {
+        let mut errors = FieldErrors::new();
+        check_length(&mut errors, "name", "", Some(1), Some(100));
+        check_length(&mut errors, "bio", &"x".repeat(200), Some(1), Some(100));
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn check_email_rejects_missing_at_or_dot() {
+        let mut errors = FieldErrors::new();
+        check_email(&mut errors, "email", "not-an-email");
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn check_email_accepts_valid_address() {
+        let mut errors = FieldErrors::new();
+        check_email(&mut errors, "email", "user@example.com");
+        assert!(errors.is_empty());
+    }
+}
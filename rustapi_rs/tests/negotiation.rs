@@ -0,0 +1,124 @@
+//! End-to-end content-negotiation tests through the real `mount_route`/`RustApi`
+//! dispatch path, using `TestClient` rather than a raw `negotiate::select` call.
+
+use rustapi_rs::prelude::*;
+
+#[rustapi_rs::get("/greeting")]
+#[rustapi_rs::format("application/json")]
+async fn greeting_json() -> Json<&'static str> {
+    Json("json")
+}
+
+#[rustapi_rs::get("/greeting")]
+#[rustapi_rs::format("text/plain")]
+async fn greeting_text() -> &'static str {
+    "text"
+}
+
+#[derive(Deserialize, Validate)]
+struct Echo {
+    value: String,
+}
+
+impl Check for Echo {
+    fn check(&self, _errors: &mut FieldErrors) {}
+}
+
+#[rustapi_rs::post("/echo")]
+#[rustapi_rs::format("application/json")]
+async fn echo_json(ValidatedJson(body): ValidatedJson<Echo>) -> Json<String> {
+    Json(body.value)
+}
+
+#[rustapi_rs::post("/echo")]
+#[rustapi_rs::format("text/plain")]
+async fn echo_text() -> &'static str {
+    "plain text handler"
+}
+
+fn app() -> RustApi {
+    RustApi::new()
+        .mount_route(greeting_json_route())
+        .mount_route(greeting_text_route())
+        .mount_route(echo_json_route())
+        .mount_route(echo_text_route())
+}
+
+#[test]
+fn accept_header_picks_the_higher_q_value_handler() {
+    let client = app().client();
+
+    let response = client
+        .get("/greeting")
+        .header("accept", "text/plain;q=0.5, application/json;q=0.9")
+        .dispatch();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.json::<String>(), "json");
+}
+
+#[test]
+fn accept_header_with_reversed_q_values_picks_text() {
+    let client = app().client();
+
+    let response = client
+        .get("/greeting")
+        .header("accept", "text/plain;q=0.9, application/json;q=0.5")
+        .dispatch();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text(), "text");
+}
+
+#[test]
+fn unmatched_accept_header_is_406() {
+    let client = app().client();
+
+    let response = client
+        .get("/greeting")
+        .header("accept", "application/xml")
+        .dispatch();
+
+    assert_eq!(response.status(), 406);
+}
+
+#[test]
+fn content_type_header_picks_the_json_post_handler() {
+    let client = app().client();
+
+    let response = client
+        .post("/echo")
+        .header("content-type", "application/json")
+        .body(r#"{"value": "hi"}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.json::<String>(), "hi");
+}
+
+#[test]
+fn content_type_header_picks_the_plain_text_post_handler() {
+    let client = app().client();
+
+    let response = client
+        .post("/echo")
+        .header("content-type", "text/plain")
+        .body("hi")
+        .dispatch();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text(), "plain text handler");
+}
+
+#[test]
+fn unmatched_content_type_header_is_406() {
+    let client = app().client();
+
+    let response = client
+        .post("/echo")
+        .header("content-type", "application/xml")
+        .body("<x/>")
+        .dispatch();
+
+    assert_eq!(response.status(), 406);
+}
@@ -0,0 +1,70 @@
+//! End-to-end tests for `Page`/`Paginated` through the real `mount_route`/
+//! `RustApi` dispatch path.
+
+use rustapi_rs::prelude::*;
+
+#[derive(Serialize, Schema)]
+struct Item {
+    id: usize,
+}
+
+#[rustapi_rs::get("/items")]
+async fn list_items(page: Page) -> Paginated<Item> {
+    const TOTAL: usize = 45;
+
+    let start = page.offset();
+    let items = (1..=page.per_page)
+        .map(|i| Item { id: start + i })
+        .filter(|item| item.id <= TOTAL)
+        .collect();
+
+    Paginated::new(items, TOTAL, page)
+}
+
+#[test]
+fn defaults_to_the_servers_configured_per_page() {
+    let client = RustApi::new().default_per_page(5).mount_route(list_items_route()).client();
+
+    let response = client.get("/items").dispatch();
+    let body: serde_json::Value = response.json();
+
+    assert_eq!(body["page"], 1);
+    assert_eq!(body["per_page"], 5);
+    assert_eq!(body["items"].as_array().unwrap().len(), 5);
+    assert_eq!(body["has_more"], true);
+}
+
+#[test]
+fn query_params_override_the_default_page_and_per_page() {
+    let client = RustApi::new().mount_route(list_items_route()).client();
+
+    let response = client.get("/items?page=2&per_page=10").dispatch();
+    let body: serde_json::Value = response.json();
+
+    assert_eq!(body["page"], 2);
+    assert_eq!(body["per_page"], 10);
+    let ids: Vec<usize> = body["items"].as_array().unwrap().iter().map(|v| v["id"].as_u64().unwrap() as usize).collect();
+    assert_eq!(ids, (11..=20).collect::<Vec<_>>());
+}
+
+#[test]
+fn per_page_is_clamped_to_the_servers_configured_max() {
+    let client = RustApi::new().max_per_page(20).mount_route(list_items_route()).client();
+
+    let response = client.get("/items?per_page=1000").dispatch();
+    let body: serde_json::Value = response.json();
+
+    assert_eq!(body["per_page"], 20);
+    assert_eq!(body["items"].as_array().unwrap().len(), 20);
+}
+
+#[test]
+fn has_more_is_false_on_the_last_page() {
+    let client = RustApi::new().default_per_page(20).mount_route(list_items_route()).client();
+
+    let response = client.get("/items?page=3").dispatch();
+    let body: serde_json::Value = response.json();
+
+    assert_eq!(body["items"].as_array().unwrap().len(), 5);
+    assert_eq!(body["has_more"], false);
+}
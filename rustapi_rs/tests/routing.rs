@@ -0,0 +1,34 @@
+//! End-to-end rank-bucket fallthrough tests through the real `mount_route`/
+//! `RustApi` dispatch path.
+
+use rustapi_rs::prelude::*;
+
+#[rustapi_rs::get("/users/me")]
+#[rustapi_rs::rank(0)]
+async fn get_current_user() -> &'static str {
+    "current user"
+}
+
+#[rustapi_rs::post("/users/{id}")]
+async fn update_user(Path(id): Path<String>) -> String {
+    format!("updated {id}")
+}
+
+fn app() -> RustApi {
+    RustApi::new()
+        .mount_route(get_current_user_route())
+        .mount_route(update_user_route())
+}
+
+#[test]
+fn method_mismatch_in_a_matched_bucket_falls_through_to_the_next_one() {
+    let client = app().client();
+
+    // `/users/me` matches the rank-0 bucket's pattern, but that bucket only
+    // has a GET handler - a POST should fall through to the rank-1
+    // `/users/{id}` bucket with `id = "me"`, not 404.
+    let response = client.post("/users/me").dispatch();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text(), "updated me");
+}
@@ -0,0 +1,67 @@
+//! End-to-end tests for the `Session` extractor through the real
+//! `mount_route`/`RustApi` dispatch path: a session cookie set on one
+//! response must be carried back on the next request to resume state.
+
+use rustapi_rs::prelude::*;
+
+#[derive(Default, Serialize, Deserialize)]
+struct Counter {
+    views: u32,
+}
+
+#[derive(Serialize, Deserialize, Schema)]
+struct CounterResponse {
+    views: u32,
+}
+
+#[rustapi_rs::get("/visits")]
+async fn visits(mut session: Session<Counter>) -> Json<CounterResponse> {
+    session.views += 1;
+    Json(CounterResponse {
+        views: session.views,
+    })
+}
+
+fn app() -> RustApi {
+    RustApi::new()
+        .cookie_secret(b"test-secret".to_vec())
+        .mount_route(visits_route())
+}
+
+#[test]
+fn first_request_starts_a_fresh_session() {
+    let client = app().client();
+    let response = client.get("/visits").dispatch();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.json::<CounterResponse>().views, 1);
+    assert!(response.header("set-cookie").unwrap().starts_with("session="));
+}
+
+#[test]
+fn session_cookie_round_trips_state_across_requests() {
+    let client = app().client();
+
+    let first = client.get("/visits").dispatch();
+    let cookie = first.header("set-cookie").unwrap().split(';').next().unwrap().to_string();
+
+    let second = client.get("/visits").header("cookie", &cookie).dispatch();
+    assert_eq!(second.json::<CounterResponse>().views, 2);
+
+    let third = client.get("/visits").header("cookie", &cookie).dispatch();
+    assert_eq!(third.json::<CounterResponse>().views, 3);
+}
+
+#[test]
+fn tampered_session_cookie_starts_a_fresh_session_instead_of_erroring() {
+    let client = app().client();
+
+    let first = client.get("/visits").dispatch();
+    let cookie = first.header("set-cookie").unwrap().split(';').next().unwrap().to_string();
+    let tampered = format!("{cookie}tampered");
+
+    let response = client.get("/visits").header("cookie", &tampered).dispatch();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.json::<CounterResponse>().views, 1);
+}
@@ -0,0 +1,81 @@
+//! End-to-end tests for `ValidatedJson`'s `Validate` + `Check` passes through
+//! the real `mount_route`/`RustApi` dispatch path.
+
+use rustapi_rs::prelude::*;
+
+#[derive(Deserialize, Validate)]
+struct Signup {
+    #[validate(length(min = 1, max = 100))]
+    name: String,
+    #[validate(email)]
+    email: String,
+    password: String,
+    confirm_password: String,
+}
+
+impl Check for Signup {
+    fn check(&self, errors: &mut FieldErrors) {
+        if self.password != self.confirm_password {
+            errors.add("confirm_password", "passwords do not match");
+        }
+    }
+}
+
+#[rustapi_rs::post("/signup")]
+async fn signup(ValidatedJson(body): ValidatedJson<Signup>) -> Json<String> {
+    Json(body.name)
+}
+
+fn app() -> RustApi {
+    RustApi::new().mount_route(signup_route())
+}
+
+#[test]
+fn valid_body_passes_both_passes() {
+    let response = app()
+        .client()
+        .post("/signup")
+        .json(&serde_json::json!({
+            "name": "Ada",
+            "email": "ada@example.com",
+            "password": "hunter22",
+            "confirm_password": "hunter22",
+        }))
+        .dispatch();
+
+    assert_eq!(response.status(), 200);
+}
+
+#[test]
+fn per_field_validate_failure_is_422() {
+    let response = app()
+        .client()
+        .post("/signup")
+        .json(&serde_json::json!({
+            "name": "",
+            "email": "not-an-email",
+            "password": "hunter22",
+            "confirm_password": "hunter22",
+        }))
+        .dispatch();
+
+    assert_eq!(response.status(), 422);
+}
+
+#[test]
+fn cross_field_check_failure_is_422() {
+    let response = app()
+        .client()
+        .post("/signup")
+        .json(&serde_json::json!({
+            "name": "Ada",
+            "email": "ada@example.com",
+            "password": "hunter22",
+            "confirm_password": "different",
+        }))
+        .dispatch();
+
+    assert_eq!(response.status(), 422);
+    let body: serde_json::Value = response.json();
+    assert!(body["errors"]["confirm_password"].is_array());
+}
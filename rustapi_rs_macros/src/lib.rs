@@ -0,0 +1,362 @@
+//! Proc-macro attributes and derives backing `rustapi_rs`'s route registration,
+//! content negotiation, ranking, and validation/schema derives.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Attribute, Expr, Fields, ItemFn, LitInt, LitStr,
+    Meta, Token,
+};
+
+/// Shared metadata pulled off the sibling `#[rustapi_rs::...]` attributes that sit
+/// between `get`/`post` and the handler body. Since `get`/`post` are the outermost
+/// attribute on a handler, they see these as plain (unexpanded) attributes and can
+/// consume them directly - `format`/`rank`/`tag`/`summary` never need to run as
+/// their own macro expansion in the common case.
+struct RouteMeta {
+    rank: Option<i64>,
+    format: Option<String>,
+    tag: Option<String>,
+    summary: Option<String>,
+}
+
+fn path_ends_with(attr: &Attribute, name: &str) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .map(|seg| seg.ident == name)
+        .unwrap_or(false)
+}
+
+fn parse_single_str_arg(attr: &Attribute) -> Option<String> {
+    let meta = &attr.meta;
+    if let Meta::List(list) = meta {
+        let lit: LitStr = syn::parse2(list.tokens.clone()).ok()?;
+        return Some(lit.value());
+    }
+    None
+}
+
+fn parse_single_int_arg(attr: &Attribute) -> Option<i64> {
+    let meta = &attr.meta;
+    if let Meta::List(list) = meta {
+        let lit: LitInt = syn::parse2(list.tokens.clone()).ok()?;
+        return lit.base10_parse().ok();
+    }
+    None
+}
+
+/// Strip `#[rustapi_rs::{format,rank,tag,summary}]` attributes from `attrs`,
+/// returning the collected metadata and the remaining (e.g. doc-comment) attrs.
+fn extract_route_meta(attrs: Vec<Attribute>) -> (RouteMeta, Vec<Attribute>) {
+    let mut meta = RouteMeta {
+        rank: None,
+        format: None,
+        tag: None,
+        summary: None,
+    };
+    let mut kept = Vec::new();
+
+    for attr in attrs {
+        if path_ends_with(&attr, "rank") {
+            meta.rank = parse_single_int_arg(&attr);
+        } else if path_ends_with(&attr, "format") {
+            meta.format = parse_single_str_arg(&attr);
+        } else if path_ends_with(&attr, "tag") {
+            meta.tag = parse_single_str_arg(&attr);
+        } else if path_ends_with(&attr, "summary") {
+            meta.summary = parse_single_str_arg(&attr);
+        } else {
+            kept.push(attr);
+        }
+    }
+
+    (meta, kept)
+}
+
+fn route_impl(method: &str, attr: TokenStream, item: TokenStream, method_ident: &str) -> TokenStream {
+    let path_lit = parse_macro_input!(attr as LitStr);
+    let path = path_lit.value();
+    let mut input = parse_macro_input!(item as ItemFn);
+
+    let (meta, kept_attrs) = extract_route_meta(std::mem::take(&mut input.attrs));
+    input.attrs = kept_attrs;
+
+    let fn_name = input.sig.ident.clone();
+    let route_fn_name = format_ident!("{}_route", fn_name);
+    let is_async = input.sig.asyncness.is_some();
+
+    let mut extract_stmts = Vec::new();
+    let mut call_args = Vec::new();
+    for (idx, arg) in input.sig.inputs.iter().enumerate() {
+        if let syn::FnArg::Typed(pat_type) = arg {
+            let ty = &pat_type.ty;
+            let tmp = format_ident!("__arg{}", idx);
+            extract_stmts.push(quote! {
+                let #tmp = match <#ty as rustapi_rs::extract::FromRequest>::from_request(&mut __parts).await {
+                    Ok(__v) => __v,
+                    Err(__resp) => return __resp,
+                };
+            });
+            call_args.push(quote! { #tmp });
+        }
+    }
+
+    let call = if is_async {
+        quote! { #fn_name(#(#call_args),*).await }
+    } else {
+        quote! { #fn_name(#(#call_args),*) }
+    };
+
+    let rank_tokens = match meta.rank {
+        Some(r) => quote! { Some(#r as i32) },
+        None => quote! { None },
+    };
+    let format_tokens = match &meta.format {
+        Some(f) => quote! { Some(#f) },
+        None => quote! { None },
+    };
+    let tag_tokens = match &meta.tag {
+        Some(t) => quote! { Some(#t) },
+        None => quote! { None },
+    };
+    let summary_tokens = match &meta.summary {
+        Some(s) => quote! { Some(#s) },
+        None => quote! { None },
+    };
+
+    let method_variant = format_ident!("{}", method_ident);
+
+    let expanded = quote! {
+        #input
+
+        #[doc(hidden)]
+        pub fn #route_fn_name() -> rustapi_rs::routing::RouteDef {
+            rustapi_rs::routing::RouteDef {
+                method: rustapi_rs::Method::#method_variant,
+                pattern: #path,
+                rank: #rank_tokens,
+                format: #format_tokens,
+                tag: #tag_tokens,
+                summary: #summary_tokens,
+                handler: std::sync::Arc::new(move |mut __parts: rustapi_rs::request::RequestParts| {
+                    Box::pin(async move {
+                        #(#extract_stmts)*
+                        let __out = #call;
+                        rustapi_rs::response::IntoResponse::into_response(__out)
+                    })
+                }),
+            }
+        }
+    };
+
+    let _ = method;
+    TokenStream::from(expanded)
+}
+
+/// `#[rustapi_rs::get("/path")]` - registers a GET route.
+#[proc_macro_attribute]
+pub fn get(attr: TokenStream, item: TokenStream) -> TokenStream {
+    route_impl("GET", attr, item, "Get")
+}
+
+/// `#[rustapi_rs::post("/path")]` - registers a POST route.
+#[proc_macro_attribute]
+pub fn post(attr: TokenStream, item: TokenStream) -> TokenStream {
+    route_impl("POST", attr, item, "Post")
+}
+
+/// `#[rustapi_rs::format("media/type")]` - constrains a route to a negotiated
+/// response (or request, for POST handlers) content type. Always consumed by
+/// the enclosing `get`/`post` attribute; this passthrough only fires if
+/// `format` is ever used without `get`/`post` on top.
+#[proc_macro_attribute]
+pub fn format(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// `#[rustapi_rs::rank(n)]` - overrides a route's automatic specificity rank.
+/// Always consumed by the enclosing `get`/`post` attribute; see `format` above.
+#[proc_macro_attribute]
+pub fn rank(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// `#[rustapi_rs::tag("...")]` - OpenAPI grouping tag, consumed by `get`/`post`.
+#[proc_macro_attribute]
+pub fn tag(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// `#[rustapi_rs::summary("...")]` - OpenAPI summary, consumed by `get`/`post`.
+#[proc_macro_attribute]
+pub fn summary(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// `#[derive(Schema)]` - minimal JSON-schema reflection used for OpenAPI output.
+#[proc_macro_derive(Schema)]
+pub fn derive_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        syn::Data::Struct(s) => match &s.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return syn::Error::new_spanned(&input, "Schema only supports structs with named fields")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(&input, "Schema only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let field_entries = fields.iter().map(|f| {
+        let ident = f.ident.as_ref().unwrap();
+        let name_str = ident.to_string();
+        let ty = &f.ty;
+        quote! {
+            (#name_str, <#ty as rustapi_rs::schema::Schema>::schema())
+        }
+    });
+
+    let expanded = quote! {
+        impl rustapi_rs::schema::Schema for #name {
+            fn schema() -> rustapi_rs::schema::SchemaNode {
+                rustapi_rs::schema::SchemaNode::object(vec![
+                    #(#field_entries),*
+                ])
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+struct FieldValidation {
+    field: syn::Ident,
+    length: Option<LengthBounds>,
+    email: bool,
+}
+
+/// `(min, max)` expressions from `#[validate(length(min = .., max = ..))]`.
+type LengthBounds = (Option<Expr>, Option<Expr>);
+
+fn parse_validate_attr(attr: &Attribute) -> syn::Result<(Option<LengthBounds>, bool)> {
+    let mut length = None;
+    let mut email = false;
+
+    let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+    for meta in nested {
+        match &meta {
+            Meta::Path(p) if p.is_ident("email") => email = true,
+            Meta::List(list) if list.path.is_ident("length") => {
+                let inner = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                let mut min = None;
+                let mut max = None;
+                for kv in inner {
+                    if let Meta::NameValue(nv) = kv {
+                        let value_expr = nv.value.clone();
+                        if nv.path.is_ident("min") {
+                            min = Some(value_expr);
+                        } else if nv.path.is_ident("max") {
+                            max = Some(value_expr);
+                        }
+                    }
+                }
+                length = Some((min, max));
+            }
+            _ => {}
+        }
+    }
+
+    Ok((length, email))
+}
+
+/// `#[derive(Validate)]` - runs `#[validate(length(...))]` / `#[validate(email)]`
+/// per-field attribute checks and collects every violation into `FieldErrors`.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        syn::Data::Struct(s) => match &s.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return syn::Error::new_spanned(&input, "Validate only supports structs with named fields")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(&input, "Validate only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut validations = Vec::new();
+    for f in fields {
+        let ident = f.ident.clone().unwrap();
+        let mut length = None;
+        let mut email = false;
+        for attr in &f.attrs {
+            if path_ends_with(attr, "validate") {
+                match parse_validate_attr(attr) {
+                    Ok((l, e)) => {
+                        length = length.or(l);
+                        email = email || e;
+                    }
+                    Err(e) => return e.to_compile_error().into(),
+                }
+            }
+        }
+        if length.is_some() || email {
+            validations.push(FieldValidation {
+                field: ident,
+                length,
+                email,
+            });
+        }
+    }
+
+    let checks = validations.iter().map(|v| {
+        let field = &v.field;
+        let field_name = field.to_string();
+        let mut stmts = Vec::new();
+
+        if let Some((min, max)) = &v.length {
+            let min_tok = min.clone().map(|e| quote! { Some(#e) }).unwrap_or(quote! { None });
+            let max_tok = max.clone().map(|e| quote! { Some(#e) }).unwrap_or(quote! { None });
+            stmts.push(quote! {
+                rustapi_rs::validate::check_length(&mut __errors, #field_name, &self.#field, #min_tok, #max_tok);
+            });
+        }
+
+        if v.email {
+            stmts.push(quote! {
+                rustapi_rs::validate::check_email(&mut __errors, #field_name, &self.#field);
+            });
+        }
+
+        quote! { #(#stmts)* }
+    });
+
+    let expanded = quote! {
+        impl rustapi_rs::validate::Validate for #name {
+            fn validate(&self) -> rustapi_rs::validate::FieldErrors {
+                let mut __errors = rustapi_rs::validate::FieldErrors::new();
+                #(#checks)*
+                __errors
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}